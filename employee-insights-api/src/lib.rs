@@ -34,14 +34,443 @@ struct DashboardStats {
     all_insights: Vec<InsightSummary>,
 }
 
+#[derive(Serialize)]
+struct TrendBucket {
+    bucket: String,
+    positif_count: i32,
+    negatif_count: i32,
+    netral_count: i32,
+}
+
+#[derive(Serialize)]
+struct RisingInsight {
+    #[serde(flatten)]
+    insight: InsightSummary,
+    deviation: f64,
+}
+
+#[derive(Serialize)]
+struct OutliersResponse {
+    rising_positive: Vec<RisingInsight>,
+    rising_negative: Vec<RisingInsight>,
+    volume_cutoff: i32,
+    percentile: u32,
+}
+
+struct SentimentRatios {
+    positive: f64,
+    negative: f64,
+    neutral: f64,
+}
+
+// Positive/negative/neutral shares of `total_pos + total_neg + total_neu`, as
+// percentages. Shared by `get_dashboard_stats` (displayed ratios) and
+// `get_insights_outliers` (baseline to compare each insight's own ratio against) so the
+// two can't drift apart.
+fn sentiment_ratios(total_pos: f64, total_neg: f64, total_neu: f64) -> SentimentRatios {
+    let total_all = total_pos + total_neg + total_neu;
+    SentimentRatios {
+        positive: total_pos / total_all * 100.0,
+        negative: total_neg / total_all * 100.0,
+        neutral: total_neu / total_all * 100.0,
+    }
+}
+
 fn log_request(req: &Request) {
     console_log!("New request: {} {}", req.method().to_string(), req.url()?.as_str());
 }
 
+// Converts a Unix epoch (seconds) into the `"YYYY-MM-DD HH:MM:SS"` form `created_at`
+// is stored as, so it can be bound directly into a `BETWEEN` comparison against that
+// TEXT column instead of being compared lexicographically against a raw integer.
+fn epoch_to_sql_datetime(epoch_secs: i64) -> String {
+    let days = epoch_secs.div_euclid(86400);
+    let secs_of_day = epoch_secs.rem_euclid(86400);
+
+    // Howard Hinnant's civil-from-days algorithm.
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if month <= 2 { y + 1 } else { y };
+
+    let hour = secs_of_day / 3600;
+    let minute = (secs_of_day % 3600) / 60;
+    let second = secs_of_day % 60;
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02}", year, month, day, hour, minute, second)
+}
+
+// Reads `start_timestamp`/`end_timestamp` query params (Unix epoch seconds), when both
+// are present, converting them to the same `"YYYY-MM-DD HH:MM:SS"` representation as the
+// `created_at` TEXT column so handlers can scope their queries to a `BETWEEN ?1 AND ?2`
+// window without comparing a string column to a raw integer.
+fn parse_time_range(req: &Request) -> Result<(Option<String>, Option<String>)> {
+    let url = req.url()?;
+    let start_timestamp = url
+        .query_pairs()
+        .find(|(key, _)| key == "start_timestamp")
+        .and_then(|(_, value)| value.parse::<i64>().ok())
+        .map(epoch_to_sql_datetime);
+    let end_timestamp = url
+        .query_pairs()
+        .find(|(key, _)| key == "end_timestamp")
+        .and_then(|(_, value)| value.parse::<i64>().ok())
+        .map(epoch_to_sql_datetime);
+
+    Ok((start_timestamp, end_timestamp))
+}
+
+// Binds `params` onto `stmt` when there are any, otherwise returns `stmt` unchanged.
+// `D1PreparedStatement::bind` errors on an empty parameter list, so every optionally
+// time-filtered query in `get_dashboard_stats` needs this same "bind only if non-empty"
+// branch; pulled out once instead of repeating the conditional per statement.
+fn bind_optional(stmt: D1PreparedStatement, params: &[JsValue]) -> Result<D1PreparedStatement> {
+    if params.is_empty() { Ok(stmt) } else { stmt.bind(params) }
+}
+
+// Below this size, compressing the body costs more than it saves.
+const COMPRESSION_THRESHOLD_BYTES: usize = 1024;
+
+// Picks the strongest encoding we support that the client advertised via `Accept-Encoding`.
+fn preferred_encoding(req: &Request) -> Option<&'static str> {
+    let accept_encoding = req.headers().get("Accept-Encoding").ok().flatten()?;
+    if accept_encoding.contains("br") {
+        Some("br")
+    } else if accept_encoding.contains("gzip") {
+        Some("gzip")
+    } else {
+        None
+    }
+}
+
+fn compress(body: &[u8], encoding: &str) -> std::io::Result<Vec<u8>> {
+    use std::io::Write;
+
+    match encoding {
+        "br" => {
+            let mut output = Vec::new();
+            brotli::BrotliCompress(&mut std::io::Cursor::new(body), &mut output, &brotli::enc::BrotliEncoderParams::default())?;
+            Ok(output)
+        }
+        "gzip" => {
+            let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(body)?;
+            encoder.finish()
+        }
+        _ => Ok(body.to_vec()),
+    }
+}
+
+// Replaces the repeated `Response::from_json(&response)` calls: compresses the serialized
+// body with the client's preferred encoding when it's large enough to be worth it, and
+// otherwise falls back to `Response::from_json`.
+fn json_response<T: Serialize>(response: &ApiResponse<T>, req: &Request) -> Result<Response> {
+    let body = serde_json::to_vec(response)?;
+
+    if body.len() < COMPRESSION_THRESHOLD_BYTES {
+        return Response::from_json(response);
+    }
+
+    let compressed = preferred_encoding(req).and_then(|encoding| compress(&body, encoding).ok().map(|bytes| (encoding, bytes)));
+
+    match compressed {
+        Some((encoding, bytes)) => {
+            let mut res = Response::from_bytes(bytes)?;
+            res.headers_mut().set("Content-Type", "application/json")?;
+            res.headers_mut().set("Content-Encoding", encoding)?;
+            Ok(res)
+        }
+        None => Response::from_json(response),
+    }
+}
+
+// Base routes whose cached entries get invalidated by `/api/insights/cache/purge`.
+// The Cache API is keyed on the full URL, so query-string variants (e.g. a date
+// range) age out on their own `max-age` rather than being purged individually.
+const CACHEABLE_ROUTES: [&str; 4] = [
+    "/api/insights/summary",
+    "/api/insights/dashboard",
+    "/api/insights/top-positive",
+    "/api/insights/top-negative",
+];
+
+// Compresses `body` for whichever encoding `req`'s `Accept-Encoding` advertises (when the
+// body's large enough to be worth it), and builds the JSON response to serve to this
+// particular request. Used both for a fresh response and for a cache hit, so the same
+// cache entry can be served compressed or uncompressed depending on who's asking.
+fn compress_body(body: Vec<u8>, cache_control: &str, req: &Request) -> Result<Response> {
+    let encoding = if body.len() >= COMPRESSION_THRESHOLD_BYTES { preferred_encoding(req) } else { None };
+    let (out_body, encoding) = match encoding.and_then(|enc| compress(&body, enc).ok().map(|bytes| (bytes, enc))) {
+        Some((bytes, enc)) => (bytes, Some(enc)),
+        None => (body, None),
+    };
+
+    let mut res = Response::from_bytes(out_body)?;
+    res.headers_mut().set("Content-Type", "application/json")?;
+    res.headers_mut().set("Cache-Control", cache_control)?;
+    if let Some(encoding) = encoding {
+        res.headers_mut().set("Content-Encoding", encoding)?;
+    }
+    Ok(res)
+}
+
+// Serializes `response`, stores the *uncompressed* body in the Cache API under the
+// request's full URL (including query params) with the given `max-age`, and returns a
+// copy compressed for this particular request's `Accept-Encoding`.
+//
+// The Cache API keys on method+URL only, with no `Vary` on encoding, so caching an
+// already-compressed body would let whichever client's encoding won the race stick to
+// every other client hitting that entry. Caching uncompressed and compressing on every
+// serve keeps the cached bytes encoding-agnostic.
+async fn cache_response<T: Serialize>(req: &Request, response: &ApiResponse<T>, max_age_secs: u32) -> Result<Response> {
+    let body = serde_json::to_vec(response)?;
+    let cache_control = format!("max-age={}", max_age_secs);
+
+    let mut to_store = Response::from_bytes(body.clone())?;
+    to_store.headers_mut().set("Content-Type", "application/json")?;
+    to_store.headers_mut().set("Cache-Control", &cache_control)?;
+    Cache::default().put(req, to_store).await?;
+
+    compress_body(body, &cache_control, req)
+}
+
+// Serves a Cache API hit: re-reads its (uncompressed) body and compresses it for this
+// request's own `Accept-Encoding`, rather than trusting whatever encoding the entry
+// happened to be stored under.
+async fn serve_cached(mut cached: Response, req: &Request) -> Result<Response> {
+    let cache_control = cached.headers().get("Cache-Control")?.unwrap_or_default();
+    let body = cached.bytes().await?;
+    compress_body(body, &cache_control, req)
+}
+
+async fn purge_cache(req: Request, _env: Env) -> Result<Response> {
+    let origin = req.url()?;
+    let cache = Cache::default();
+
+    let mut purged = 0;
+    for path in CACHEABLE_ROUTES {
+        let mut route_url = origin.clone();
+        route_url.set_path(path);
+        route_url.set_query(None);
+
+        let cache_req = Request::new(route_url.as_str(), Method::Get)?;
+        if cache.delete(&cache_req, false).await? {
+            purged += 1;
+        }
+    }
+
+    let response = ApiResponse {
+        success: true,
+        data: purged,
+        message: "Cache purged".to_string(),
+    };
+
+    json_response(&response, &req)
+}
+
+// Request/error counts and a latency histogram for one route, persisted in the
+// `MetricsStore` Durable Object so counts survive across worker invocations.
+#[derive(Serialize, Deserialize, Default, Clone)]
+struct RouteMetrics {
+    requests: u64,
+    errors: u64,
+    bucket_le_0_1: u64,
+    bucket_le_0_5: u64,
+    bucket_le_1: u64,
+    bucket_le_5: u64,
+    bucket_le_inf: u64,
+    sum_seconds: f64,
+}
+
+impl RouteMetrics {
+    fn record(&mut self, elapsed_secs: f64, is_error: bool) {
+        self.requests += 1;
+        if is_error {
+            self.errors += 1;
+        }
+        self.sum_seconds += elapsed_secs;
+        if elapsed_secs <= 0.1 {
+            self.bucket_le_0_1 += 1;
+        }
+        if elapsed_secs <= 0.5 {
+            self.bucket_le_0_5 += 1;
+        }
+        if elapsed_secs <= 1.0 {
+            self.bucket_le_1 += 1;
+        }
+        if elapsed_secs <= 5.0 {
+            self.bucket_le_5 += 1;
+        }
+        self.bucket_le_inf += 1;
+    }
+}
+
+const METRIC_ROUTES: [&str; 9] = [
+    "insights_summary",
+    "insights_dashboard",
+    "insights_trends",
+    "insights_outliers",
+    "insights_top_positive",
+    "insights_top_negative",
+    "insights_by_word",
+    "insights_cache_purge",
+    "root",
+];
+
+// Requests the `MetricsStore` Durable Object to record or read back route metrics. A
+// plain KV get-then-put races under concurrent requests (one request's increment can
+// clobber another's) and Cloudflare rate-limits writes to a single KV key, so a busy
+// route would start silently dropping updates under load. Durable Objects serialize all
+// access to a given instance, which gives us the single writer this needs.
+#[derive(Serialize, Deserialize)]
+enum MetricsRequest {
+    Record { route: String, elapsed_secs: f64, is_error: bool },
+    ReadAll,
+}
+
+#[durable_object]
+pub struct MetricsStore {
+    state: State,
+}
+
+#[durable_object]
+impl DurableObject for MetricsStore {
+    fn new(state: State, _env: Env) -> Self {
+        Self { state }
+    }
+
+    async fn fetch(&mut self, mut req: Request) -> Result<Response> {
+        match req.json::<MetricsRequest>().await? {
+            MetricsRequest::Record { route, elapsed_secs, is_error } => {
+                let key = format!("route:{}", route);
+                let mut metrics: RouteMetrics = self.state.storage().get(&key).await.unwrap_or_default();
+                metrics.record(elapsed_secs, is_error);
+                self.state.storage().put(&key, &metrics).await?;
+                Response::ok("")
+            }
+            MetricsRequest::ReadAll => {
+                let mut all: std::collections::HashMap<String, RouteMetrics> = std::collections::HashMap::new();
+                for route in METRIC_ROUTES {
+                    let key = format!("route:{}", route);
+                    all.insert(route.to_string(), self.state.storage().get(&key).await.unwrap_or_default());
+                }
+                Response::from_json(&all)
+            }
+        }
+    }
+}
+
+// There's only ever one `MetricsStore` instance: all routes' counts live together so
+// `get_metrics` can read them back with a single round trip.
+fn metrics_stub(env: &Env) -> Result<Stub> {
+    env.durable_object("METRICS")?.id_from_name("global")?.get_stub()
+}
+
+async fn send_metrics_request(env: &Env, path: &str, body: &MetricsRequest) -> Result<Response> {
+    let mut init = RequestInit::new();
+    init.with_method(Method::Post)
+        .with_body(Some(JsValue::from_str(&serde_json::to_string(body)?)));
+
+    let req = Request::new_with_init(&format!("https://metrics/{}", path), &init)?;
+    metrics_stub(env)?.fetch_with_request(req).await
+}
+
+async fn record_metrics(env: &Env, route: &str, elapsed_secs: f64, status: u16) -> Result<()> {
+    let body = MetricsRequest::Record {
+        route: route.to_string(),
+        elapsed_secs,
+        is_error: status >= 400,
+    };
+    send_metrics_request(env, "record", &body).await?;
+
+    Ok(())
+}
+
+// Times `handler`, then records its outcome under `route` in the `MetricsStore`
+// Durable Object before returning the response unchanged.
+async fn instrument<F, Fut>(route: &'static str, req: Request, env: Env, handler: F) -> Result<Response>
+where
+    F: FnOnce(Request, Env) -> Fut,
+    Fut: std::future::Future<Output = Result<Response>>,
+{
+    let started_at = Date::now().as_millis() as f64;
+    let result = handler(req, env.clone()).await;
+    let elapsed_secs = (Date::now().as_millis() as f64 - started_at) / 1000.0;
+
+    let status = match &result {
+        Ok(response) => response.status_code(),
+        Err(_) => 500,
+    };
+
+    if let Err(err) = record_metrics(&env, route, elapsed_secs, status).await {
+        console_log!("Failed to record metrics for {}: {}", route, err);
+    }
+
+    result
+}
+
+async fn get_root(_req: Request, _env: Env) -> Result<Response> {
+    Response::ok("Employee Insights API v1.0 - Powered by Rust & Cloudflare Workers")
+}
+
+async fn get_metrics(_req: Request, env: Env) -> Result<Response> {
+    let mut res = send_metrics_request(&env, "read", &MetricsRequest::ReadAll).await?;
+    let all: std::collections::HashMap<String, RouteMetrics> = res.json().await?;
+
+    let mut requests_lines = String::new();
+    let mut errors_lines = String::new();
+    let mut histogram_lines = String::new();
+
+    for route in METRIC_ROUTES {
+        let metrics = all.get(route).cloned().unwrap_or_default();
+
+        requests_lines.push_str(&format!("employee_insights_requests_total{{route=\"{}\"}} {}\n", route, metrics.requests));
+        errors_lines.push_str(&format!("employee_insights_errors_total{{route=\"{}\"}} {}\n", route, metrics.errors));
+
+        for (le, count) in [
+            ("0.1", metrics.bucket_le_0_1),
+            ("0.5", metrics.bucket_le_0_5),
+            ("1", metrics.bucket_le_1),
+            ("5", metrics.bucket_le_5),
+            ("+Inf", metrics.bucket_le_inf),
+        ] {
+            histogram_lines.push_str(&format!(
+                "employee_insights_request_duration_seconds_bucket{{route=\"{}\",le=\"{}\"}} {}\n",
+                route, le, count
+            ));
+        }
+        histogram_lines.push_str(&format!("employee_insights_request_duration_seconds_sum{{route=\"{}\"}} {}\n", route, metrics.sum_seconds));
+        histogram_lines.push_str(&format!("employee_insights_request_duration_seconds_count{{route=\"{}\"}} {}\n", route, metrics.requests));
+    }
+
+    let body = format!(
+        "# HELP employee_insights_requests_total Total requests handled per route.\n\
+         # TYPE employee_insights_requests_total counter\n\
+         {}\n\
+         # HELP employee_insights_errors_total Total error (4xx/5xx) responses per route.\n\
+         # TYPE employee_insights_errors_total counter\n\
+         {}\n\
+         # HELP employee_insights_request_duration_seconds Request latency distribution, in seconds.\n\
+         # TYPE employee_insights_request_duration_seconds histogram\n\
+         {}",
+        requests_lines, errors_lines, histogram_lines
+    );
+
+    let mut headers = Headers::new();
+    headers.set("Content-Type", "text/plain; version=0.0.4")?;
+    Ok(Response::ok(body)?.with_headers(headers))
+}
+
 #[event(fetch)]
 pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
     console_error_panic_hook::set_once();
-    
+
     // Enable CORS for all origins
     let cors = Cors::new()
         .with_origins(vec!["*"])
@@ -49,38 +478,75 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
         .with_headers(vec!["*"]);
 
     Router::new()
-        .get_async("/api/insights/summary", get_insights_summary)
-        .get_async("/api/insights/dashboard", get_dashboard_stats)
-        .get_async("/api/insights/top-positive", get_top_positive)
-        .get_async("/api/insights/top-negative", get_top_negative)
-        .get_async("/api/insights/:word", get_insight_by_word)
-        .get("/", |_, _| Response::ok("Employee Insights API v1.0 - Powered by Rust & Cloudflare Workers"))
+        .get_async("/api/insights/summary", |req, env| instrument("insights_summary", req, env, get_insights_summary))
+        .get_async("/api/insights/dashboard", |req, env| instrument("insights_dashboard", req, env, get_dashboard_stats))
+        .get_async("/api/insights/trends", |req, env| instrument("insights_trends", req, env, get_insights_trends))
+        .get_async("/api/insights/outliers", |req, env| instrument("insights_outliers", req, env, get_insights_outliers))
+        .get_async("/api/insights/top-positive", |req, env| instrument("insights_top_positive", req, env, get_top_positive))
+        .get_async("/api/insights/top-negative", |req, env| instrument("insights_top_negative", req, env, get_top_negative))
+        .get_async("/api/insights/:word", |req, env| instrument("insights_by_word", req, env, get_insight_by_word))
+        .post_async("/api/insights/cache/purge", |req, env| instrument("insights_cache_purge", req, env, purge_cache))
+        .get_async("/", |req, env| instrument("root", req, env, get_root))
+        .get_async("/metrics", get_metrics)
         .run(req, env)
         .await
 }
 
-async fn get_insights_summary(_req: Request, env: Env) -> Result<Response> {
+async fn get_insights_summary(req: Request, env: Env) -> Result<Response> {
+    if let Some(cached) = Cache::default().get(&req, false).await? {
+        return serve_cached(cached, &req).await;
+    }
+
     let d1 = env.d1("DB")?;
-    
-    let statement = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary ORDER BY total_count DESC");
-    
-    let result = statement.all().await?;
+
+    let (start_timestamp, end_timestamp) = parse_time_range(&req)?;
+
+    let mut query = "SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary".to_string();
+    let mut params: Vec<JsValue> = Vec::new();
+    if let (Some(start), Some(end)) = (start_timestamp, end_timestamp) {
+        query.push_str(" WHERE created_at BETWEEN ?1 AND ?2");
+        params.push(start.into());
+        params.push(end.into());
+    }
+    query.push_str(" ORDER BY total_count DESC");
+
+    let statement = d1.prepare(&query);
+    let result = if params.is_empty() {
+        statement.all().await?
+    } else {
+        statement.bind(&params)?.all().await?
+    };
     let insights: Vec<InsightSummary> = result.results()?;
-    
+
     let response = ApiResponse {
         success: true,
         data: insights,
         message: "Successfully retrieved all insights summary".to_string(),
     };
-    
-    Response::from_json(&response)
+
+    cache_response(&req, &response, 300).await
 }
 
-async fn get_dashboard_stats(_req: Request, env: Env) -> Result<Response> {
+async fn get_dashboard_stats(req: Request, env: Env) -> Result<Response> {
+    if let Some(cached) = Cache::default().get(&req, false).await? {
+        return serve_cached(cached, &req).await;
+    }
+
     let d1 = env.d1("DB")?;
-    
+
+    let (start_timestamp, end_timestamp) = parse_time_range(&req)?;
+    let time_filter = if start_timestamp.is_some() && end_timestamp.is_some() {
+        " WHERE created_at BETWEEN ?1 AND ?2"
+    } else {
+        ""
+    };
+    let time_params: Vec<JsValue> = match (start_timestamp, end_timestamp) {
+        (Some(start), Some(end)) => vec![start.into(), end.into()],
+        _ => Vec::new(),
+    };
+
     // Get total counts
-    let total_insights_stmt = d1.prepare("SELECT COUNT(*) as count FROM insight_summary");
+    let total_insights_stmt = bind_optional(d1.prepare(&format!("SELECT COUNT(*) as count FROM insight_summary{}", time_filter)), &time_params)?;
     let total_insights_result = total_insights_stmt.first::<serde_json::Value>(None).await?;
     let total_insights = total_insights_result
         .unwrap()
@@ -88,9 +554,9 @@ async fn get_dashboard_stats(_req: Request, env: Env) -> Result<Response> {
         .unwrap()
         .as_i64()
         .unwrap() as i32;
-    
+
     // Get total feedback count
-    let total_feedback_stmt = d1.prepare("SELECT SUM(total_count) as total FROM insight_summary");
+    let total_feedback_stmt = bind_optional(d1.prepare(&format!("SELECT SUM(total_count) as total FROM insight_summary{}", time_filter)), &time_params)?;
     let total_feedback_result = total_feedback_stmt.first::<serde_json::Value>(None).await?;
     let total_feedback = total_feedback_result
         .unwrap()
@@ -98,36 +564,58 @@ async fn get_dashboard_stats(_req: Request, env: Env) -> Result<Response> {
         .unwrap()
         .as_i64()
         .unwrap() as i32;
-    
+
     // Get overall sentiment ratio
-    let sentiment_stmt = d1.prepare("SELECT SUM(positif_count) as pos, SUM(negatif_count) as neg, SUM(netral_count) as neu FROM insight_summary");
+    let sentiment_stmt = bind_optional(
+        d1.prepare(&format!("SELECT SUM(positif_count) as pos, SUM(negatif_count) as neg, SUM(netral_count) as neu FROM insight_summary{}", time_filter)),
+        &time_params,
+    )?;
     let sentiment_result = sentiment_stmt.first::<serde_json::Value>(None).await?;
     let sentiment_data = sentiment_result.unwrap();
-    
+
     let total_pos = sentiment_data.get("pos").unwrap().as_i64().unwrap() as f64;
     let total_neg = sentiment_data.get("neg").unwrap().as_i64().unwrap() as f64;
     let total_neu = sentiment_data.get("neu").unwrap().as_i64().unwrap() as f64;
-    let total_all = total_pos + total_neg + total_neu;
-    
-    let positive_ratio = (total_pos / total_all * 100.0 * 100.0).round() / 100.0;
-    let negative_ratio = (total_neg / total_all * 100.0 * 100.0).round() / 100.0;
-    let neutral_ratio = (total_neu / total_all * 100.0 * 100.0).round() / 100.0;
-    
+    let ratios = sentiment_ratios(total_pos, total_neg, total_neu);
+
+    let positive_ratio = (ratios.positive * 100.0).round() / 100.0;
+    let negative_ratio = (ratios.negative * 100.0).round() / 100.0;
+    let neutral_ratio = (ratios.neutral * 100.0).round() / 100.0;
+
     // Get top positive insights
-    let top_positive_stmt = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary WHERE positif_percentage > 70 ORDER BY positif_percentage DESC, total_count DESC LIMIT 5");
+    let top_positive_condition = if time_filter.is_empty() {
+        " WHERE positif_percentage > 70".to_string()
+    } else {
+        format!("{} AND positif_percentage > 70", time_filter)
+    };
+    let top_positive_stmt = bind_optional(
+        d1.prepare(&format!("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary{} ORDER BY positif_percentage DESC, total_count DESC LIMIT 5", top_positive_condition)),
+        &time_params,
+    )?;
     let top_positive_result = top_positive_stmt.all().await?;
     let top_positive_insights: Vec<InsightSummary> = top_positive_result.results()?;
-    
+
     // Get top negative insights
-    let top_negative_stmt = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary WHERE negatif_percentage > 70 ORDER BY negatif_percentage DESC, total_count DESC LIMIT 5");
+    let top_negative_condition = if time_filter.is_empty() {
+        " WHERE negatif_percentage > 70".to_string()
+    } else {
+        format!("{} AND negatif_percentage > 70", time_filter)
+    };
+    let top_negative_stmt = bind_optional(
+        d1.prepare(&format!("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary{} ORDER BY negatif_percentage DESC, total_count DESC LIMIT 5", top_negative_condition)),
+        &time_params,
+    )?;
     let top_negative_result = top_negative_stmt.all().await?;
     let top_negative_insights: Vec<InsightSummary> = top_negative_result.results()?;
-    
+
     // Get all insights for charts
-    let all_insights_stmt = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary ORDER BY total_count DESC LIMIT 20");
+    let all_insights_stmt = bind_optional(
+        d1.prepare(&format!("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary{} ORDER BY total_count DESC LIMIT 20", time_filter)),
+        &time_params,
+    )?;
     let all_insights_result = all_insights_stmt.all().await?;
     let all_insights: Vec<InsightSummary> = all_insights_result.results()?;
-    
+
     let dashboard_stats = DashboardStats {
         total_insights,
         total_feedback,
@@ -138,75 +626,419 @@ async fn get_dashboard_stats(_req: Request, env: Env) -> Result<Response> {
         top_negative_insights,
         all_insights,
     };
-    
+
     let response = ApiResponse {
         success: true,
         data: dashboard_stats,
         message: "Successfully retrieved dashboard statistics".to_string(),
     };
-    
-    Response::from_json(&response)
+
+    cache_response(&req, &response, 300).await
 }
 
-async fn get_top_positive(_req: Request, env: Env) -> Result<Response> {
+// Buckets sentiment counts by day/week/month so the frontend can chart sentiment
+// over time instead of only the current snapshot.
+async fn get_insights_trends(req: Request, env: Env) -> Result<Response> {
     let d1 = env.d1("DB")?;
-    
-    let statement = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary WHERE positif_percentage > 70 ORDER BY positif_percentage DESC, total_count DESC LIMIT 10");
-    
+
+    let granularity = req
+        .url()?
+        .query_pairs()
+        .find(|(key, _)| key == "granularity")
+        .map(|(_, value)| value.to_string())
+        .unwrap_or_else(|| "day".to_string());
+
+    let bucket_expr = match granularity.as_str() {
+        "week" => "strftime('%Y-W%W', created_at)",
+        "month" => "strftime('%Y-%m', created_at)",
+        _ => "strftime('%Y-%m-%d', created_at)",
+    };
+
+    let (start_timestamp, end_timestamp) = parse_time_range(&req)?;
+    let mut query = format!(
+        "SELECT {} as bucket, SUM(positif_count) as positif_count, SUM(negatif_count) as negatif_count, SUM(netral_count) as netral_count FROM insight_summary",
+        bucket_expr
+    );
+    let mut params: Vec<JsValue> = Vec::new();
+    if let (Some(start), Some(end)) = (start_timestamp, end_timestamp) {
+        query.push_str(" WHERE created_at BETWEEN ?1 AND ?2");
+        params.push(start.into());
+        params.push(end.into());
+    }
+    query.push_str(&format!(" GROUP BY {} ORDER BY bucket ASC", bucket_expr));
+
+    let statement = d1.prepare(&query);
+    let result = if params.is_empty() {
+        statement.all().await?
+    } else {
+        statement.bind(&params)?.all().await?
+    };
+    let trends: Vec<TrendBucket> = result.results()?;
+
+    let response = ApiResponse {
+        success: true,
+        data: trends,
+        message: "Successfully retrieved insight trends".to_string(),
+    };
+
+    json_response(&response, &req)
+}
+
+// The `total_count` at the top `percentile` percent of `sorted_counts` (ascending).
+// Pulled out of `get_insights_outliers` so the cutoff math can be unit-tested directly.
+fn percentile_cutoff(sorted_counts: &[i32], percentile: u32) -> i32 {
+    let cutoff_index = (sorted_counts.len() as f64 * (100 - percentile) as f64 / 100.0).floor() as usize;
+    sorted_counts[cutoff_index.min(sorted_counts.len() - 1)]
+}
+
+// Flags high-volume insights whose sentiment skews strongly positive or negative,
+// instead of relying on the fixed `positif_percentage > 70` threshold used for the
+// top-positive/top-negative endpoints.
+async fn get_insights_outliers(req: Request, env: Env) -> Result<Response> {
+    let d1 = env.d1("DB")?;
+
+    let percentile = req
+        .url()?
+        .query_pairs()
+        .find(|(key, _)| key == "percentile")
+        .and_then(|(_, value)| value.parse::<u32>().ok())
+        .unwrap_or(33)
+        .clamp(1, 99);
+
+    let statement = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary");
     let result = statement.all().await?;
     let insights: Vec<InsightSummary> = result.results()?;
-    
+
+    if insights.is_empty() {
+        let response = ApiResponse {
+            success: true,
+            data: OutliersResponse {
+                rising_positive: Vec::new(),
+                rising_negative: Vec::new(),
+                volume_cutoff: 0,
+                percentile,
+            },
+            message: "No insights available to analyze".to_string(),
+        };
+
+        return json_response(&response, &req);
+    }
+
+    // Volume cutoff: the `total_count` at the top `percentile` percent of the distribution.
+    let mut counts: Vec<i32> = insights.iter().map(|insight| insight.total_count).collect();
+    counts.sort_unstable();
+    let volume_cutoff = percentile_cutoff(&counts, percentile);
+
+    // Overall sentiment ratio, via the same helper `get_dashboard_stats` uses.
+    let total_pos: f64 = insights.iter().map(|insight| insight.positif_count as f64).sum();
+    let total_neg: f64 = insights.iter().map(|insight| insight.negatif_count as f64).sum();
+    let total_neu: f64 = insights.iter().map(|insight| insight.netral_count as f64).sum();
+    let ratios = sentiment_ratios(total_pos, total_neg, total_neu);
+    let mean_positive_ratio = ratios.positive;
+    let mean_negative_ratio = ratios.negative;
+
+    let mut rising_positive: Vec<RisingInsight> = Vec::new();
+    let mut rising_negative: Vec<RisingInsight> = Vec::new();
+
+    for insight in insights {
+        if insight.total_count < volume_cutoff {
+            continue;
+        }
+
+        let positif_percentage = insight.positif_percentage;
+        let negatif_percentage = insight.negatif_percentage;
+
+        if positif_percentage > negatif_percentage {
+            let deviation = positif_percentage - mean_positive_ratio;
+            if deviation > 0.0 {
+                rising_positive.push(RisingInsight { insight, deviation });
+            }
+        } else if negatif_percentage > positif_percentage {
+            let deviation = negatif_percentage - mean_negative_ratio;
+            if deviation > 0.0 {
+                rising_negative.push(RisingInsight { insight, deviation });
+            }
+        }
+    }
+
+    rising_positive.sort_by(|a, b| b.deviation.partial_cmp(&a.deviation).unwrap_or(std::cmp::Ordering::Equal));
+    rising_negative.sort_by(|a, b| b.deviation.partial_cmp(&a.deviation).unwrap_or(std::cmp::Ordering::Equal));
+
+    let response = ApiResponse {
+        success: true,
+        data: OutliersResponse {
+            rising_positive,
+            rising_negative,
+            volume_cutoff,
+            percentile,
+        },
+        message: "Successfully retrieved rising insights".to_string(),
+    };
+
+    json_response(&response, &req)
+}
+
+async fn get_top_positive(req: Request, env: Env) -> Result<Response> {
+    if let Some(cached) = Cache::default().get(&req, false).await? {
+        return serve_cached(cached, &req).await;
+    }
+
+    let d1 = env.d1("DB")?;
+
+    let (start_timestamp, end_timestamp) = parse_time_range(&req)?;
+    let mut query = "SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary WHERE positif_percentage > 70".to_string();
+    let mut params: Vec<JsValue> = Vec::new();
+    if let (Some(start), Some(end)) = (start_timestamp, end_timestamp) {
+        query.push_str(" AND created_at BETWEEN ?1 AND ?2");
+        params.push(start.into());
+        params.push(end.into());
+    }
+    query.push_str(" ORDER BY positif_percentage DESC, total_count DESC LIMIT 10");
+
+    let statement = d1.prepare(&query);
+    let result = if params.is_empty() {
+        statement.all().await?
+    } else {
+        statement.bind(&params)?.all().await?
+    };
+    let insights: Vec<InsightSummary> = result.results()?;
+
     let response = ApiResponse {
         success: true,
         data: insights,
         message: "Successfully retrieved top positive insights".to_string(),
     };
-    
-    Response::from_json(&response)
+
+    cache_response(&req, &response, 300).await
 }
 
-async fn get_top_negative(_req: Request, env: Env) -> Result<Response> {
+async fn get_top_negative(req: Request, env: Env) -> Result<Response> {
+    if let Some(cached) = Cache::default().get(&req, false).await? {
+        return serve_cached(cached, &req).await;
+    }
+
     let d1 = env.d1("DB")?;
-    
-    let statement = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary WHERE negatif_percentage > 70 ORDER BY negatif_percentage DESC, total_count DESC LIMIT 10");
-    
-    let result = statement.all().await?;
+
+    let (start_timestamp, end_timestamp) = parse_time_range(&req)?;
+    let mut query = "SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary WHERE negatif_percentage > 70".to_string();
+    let mut params: Vec<JsValue> = Vec::new();
+    if let (Some(start), Some(end)) = (start_timestamp, end_timestamp) {
+        query.push_str(" AND created_at BETWEEN ?1 AND ?2");
+        params.push(start.into());
+        params.push(end.into());
+    }
+    query.push_str(" ORDER BY negatif_percentage DESC, total_count DESC LIMIT 10");
+
+    let statement = d1.prepare(&query);
+    let result = if params.is_empty() {
+        statement.all().await?
+    } else {
+        statement.bind(&params)?.all().await?
+    };
     let insights: Vec<InsightSummary> = result.results()?;
-    
+
     let response = ApiResponse {
         success: true,
         data: insights,
         message: "Successfully retrieved top negative insights".to_string(),
     };
-    
-    Response::from_json(&response)
+
+    cache_response(&req, &response, 300).await
+}
+
+// Escapes `%`/`_`/`\` so `input` can be safely spliced into a `LIKE ... ESCAPE '\'`
+// pattern as a literal, rather than having those characters read as wildcards.
+fn escape_like_pattern(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+    for c in input.chars() {
+        if c == '\\' || c == '%' || c == '_' {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+// Lowercases and splits on non-alphanumeric boundaries so "work-life" and "Work Life"
+// tokenize the same way for scoring.
+fn tokenize(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut prev = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cur = row[j];
+            row[j] = if a[i - 1] == b[j - 1] {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j - 1])
+            };
+            prev = cur;
+        }
+    }
+
+    row[b.len()]
+}
+
+// Scores a candidate's best-matching token against the query by bounded edit distance
+// plus a substring bonus, rejecting pairs whose distance is too large relative to length.
+fn score_candidate(query: &str, candidate: &str) -> Option<f64> {
+    let query_tokens = tokenize(query);
+    let candidate_tokens = tokenize(candidate);
+
+    let mut best_score: Option<f64> = None;
+    for q in &query_tokens {
+        for c in &candidate_tokens {
+            let len = q.len().max(c.len());
+            if len == 0 {
+                continue;
+            }
+
+            let dist = levenshtein(q, c);
+            let max_dist = std::cmp::max(1, len / 3);
+            if dist > max_dist {
+                continue;
+            }
+
+            let substring_match = if c.contains(q.as_str()) || q.contains(c.as_str()) { 1.0 } else { 0.0 };
+            let score = (1.0 - dist as f64 / len as f64) * 0.7 + substring_match * 0.3;
+            best_score = Some(best_score.map_or(score, |best: f64| best.max(score)));
+        }
+    }
+
+    best_score
+}
+
+#[derive(Serialize)]
+struct ScoredInsight {
+    #[serde(flatten)]
+    insight: InsightSummary,
+    score: f64,
 }
 
 async fn get_insight_by_word(req: Request, env: Env) -> Result<Response> {
     let d1 = env.d1("DB")?;
-    
+
     // Extract word from URL path
-    if let Some(word) = req.param("word") {
-        let statement = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary WHERE wordInsight LIKE ?1 ORDER BY total_count DESC");
-        
-        let search_term = format!("%{}%", word);
-        let result = statement.bind(&[search_term.into()])?.all().await?;
-        let insights: Vec<InsightSummary> = result.results()?;
-        
+    if let Some(word) = req.param("word").map(|w| w.to_string()) {
+        let url = req.url()?;
+        let limit = url
+            .query_pairs()
+            .find(|(key, _)| key == "limit")
+            .and_then(|(_, value)| value.parse::<usize>().ok())
+            .unwrap_or(10);
+        let min_score = url
+            .query_pairs()
+            .find(|(key, _)| key == "min_score")
+            .and_then(|(_, value)| value.parse::<f64>().ok())
+            .unwrap_or(0.4);
+
+        // Pre-filter candidates by shared first letter or substring to keep the D1
+        // result set small before scoring the rest in memory. `word` is escaped first so
+        // a literal `%`/`_` in it can't change the wildcard semantics of the pattern.
+        let first_letter = word.chars().next().map(|c| c.to_lowercase().to_string()).unwrap_or_default();
+        let first_letter_pattern = format!("{}%", escape_like_pattern(&first_letter));
+        let substring_pattern = format!("%{}%", escape_like_pattern(&word));
+
+        let statement = d1.prepare("SELECT id, wordInsight as word_insight, total_count, positif_count, negatif_count, netral_count, positif_percentage, negatif_percentage, netral_percentage, created_at FROM insight_summary WHERE wordInsight LIKE ?1 ESCAPE '\\' OR wordInsight LIKE ?2 ESCAPE '\\'");
+        let result = statement.bind(&[first_letter_pattern.into(), substring_pattern.into()])?.all().await?;
+        let candidates: Vec<InsightSummary> = result.results()?;
+
+        let mut scored: Vec<ScoredInsight> = candidates
+            .into_iter()
+            .filter_map(|insight| {
+                let score = score_candidate(&word, &insight.word_insight)?;
+                if score < min_score {
+                    return None;
+                }
+                Some(ScoredInsight { insight, score })
+            })
+            .collect();
+
+        scored.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.insight.total_count.cmp(&a.insight.total_count))
+        });
+        scored.truncate(limit);
+
         let response = ApiResponse {
             success: true,
-            data: insights,
+            data: scored,
             message: format!("Successfully retrieved insights for '{}'", word),
         };
-        
-        Response::from_json(&response)
+
+        json_response(&response, &req)
     } else {
         let response = ApiResponse {
             success: false,
             data: Vec::<InsightSummary>::new(),
             message: "Word parameter is required".to_string(),
         };
-        
-        Response::from_json(&response)?.with_status(400)
+
+        json_response(&response, &req)?.with_status(400)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn epoch_to_sql_datetime_formats_known_instant() {
+        // 2023-11-14 22:13:20 UTC
+        assert_eq!(epoch_to_sql_datetime(1_700_000_000), "2023-11-14 22:13:20");
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn epoch_to_sql_datetime_handles_epoch_zero() {
+        assert_eq!(epoch_to_sql_datetime(0), "1970-01-01 00:00:00");
+    }
+
+    #[test]
+    fn levenshtein_distance_of_identical_strings_is_zero() {
+        assert_eq!(levenshtein("worklife", "worklife"), 0);
+    }
+
+    #[test]
+    fn levenshtein_counts_single_edits() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("work", "wrok"), 2);
+    }
+
+    #[test]
+    fn score_candidate_rejects_unrelated_words() {
+        assert_eq!(score_candidate("worklife", "compensation"), None);
+    }
+
+    #[test]
+    fn score_candidate_scores_exact_match_highest() {
+        let exact = score_candidate("worklife", "worklife").expect("should match");
+        let fuzzy = score_candidate("worklife", "worklif").expect("should still match");
+        assert!(exact > fuzzy);
+    }
+
+    #[test]
+    fn percentile_cutoff_at_33rd_percentile() {
+        let counts = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+        assert_eq!(percentile_cutoff(&counts, 33), 7);
+    }
+
+    #[test]
+    fn percentile_cutoff_of_single_count_is_that_count() {
+        let counts = [5];
+        assert_eq!(percentile_cutoff(&counts, 99), 5);
+    }
+}